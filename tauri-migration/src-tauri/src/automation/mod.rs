@@ -0,0 +1,109 @@
+// src-tauri/src/automation/mod.rs — n8n workflow trigger and automation bridge.
+//
+// `main` starts `n8n.exe start --tunnel` but the backend never talked to it. This
+// stores workflow definitions (name, webhook URL, trigger event, payload
+// template) in managed state, fires them by POSTing to their webhook URLs,
+// and binds them to app events so e.g. a `trade-price` crossing a threshold
+// or a `research-end` can auto-run a workflow without the user leaving the app.
+
+mod types;
+
+pub use types::*;
+
+use crate::config::AppState;
+use tauri::{Emitter, State};
+
+/// Registers a workflow so it can be triggered by name or fired automatically
+/// when `trigger_event` next happens.
+#[tauri::command]
+pub fn register_workflow(workflow: Workflow, store: State<'_, WorkflowStore>) {
+    store.add(workflow);
+}
+
+#[tauri::command]
+pub fn unregister_workflow(name: String, store: State<'_, WorkflowStore>) {
+    store.remove(&name);
+}
+
+#[tauri::command]
+pub fn list_workflows(store: State<'_, WorkflowStore>) -> Vec<Workflow> {
+    store.list()
+}
+
+/// POSTs `payload` to `name`'s webhook and streams back n8n's response body
+/// as a `workflow-result` event, mirroring how the streaming commands report
+/// through window events rather than a single round-trip return value.
+#[tauri::command]
+pub async fn trigger_workflow(
+    name: String,
+    payload: serde_json::Value,
+    window: tauri::WebviewWindow,
+    state: State<'_, AppState>,
+    store: State<'_, WorkflowStore>,
+) -> Result<(), String> {
+    let workflow = store
+        .list()
+        .into_iter()
+        .find(|w| w.name == name)
+        .ok_or_else(|| format!("no workflow named {name}"))?;
+
+    run_workflow(&state.http, &window, &workflow, payload).await
+}
+
+async fn run_workflow(
+    http: &reqwest::Client,
+    window: &tauri::WebviewWindow,
+    workflow: &Workflow,
+    event_payload: serde_json::Value,
+) -> Result<(), String> {
+    // Only merge when the event actually carries an object payload (e.g.
+    // `trade-price`'s `{price, change}`). Events like `research-end` fire
+    // with `()` → `Value::Null`, which must leave a configured
+    // `payload_template` untouched rather than overwrite it with null.
+    let mut body = workflow.payload_template.clone();
+    if let Some(extra) = event_payload.as_object() {
+        match body.as_object_mut() {
+            Some(obj) => obj.extend(extra.clone()),
+            None => body = event_payload,
+        }
+    }
+
+    let res = http
+        .post(&workflow.webhook_url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("n8n webhook request failed: {e}"))?;
+
+    let result = res
+        .json::<serde_json::Value>()
+        .await
+        .unwrap_or(serde_json::Value::Null);
+
+    window
+        .emit("workflow-result", serde_json::json!({ "workflow": workflow.name, "result": result }))
+        .ok();
+    Ok(())
+}
+
+/// Fires every workflow bound to `event` that's due to run — untresholded
+/// workflows fire on every occurrence; thresholded ones only on the tick
+/// `payload` crosses their configured threshold. Called from event listeners
+/// set up in `main`.
+pub async fn fire_bound_workflows(
+    http: &reqwest::Client,
+    window: &tauri::WebviewWindow,
+    store: &WorkflowStore,
+    event: &str,
+    payload: serde_json::Value,
+) {
+    let runs = store
+        .due_for(event, &payload)
+        .into_iter()
+        .map(|workflow| async move {
+            if let Err(e) = run_workflow(http, window, &workflow, payload.clone()).await {
+                eprintln!("workflow {} failed: {e}", workflow.name);
+            }
+        });
+    futures_util::future::join_all(runs).await;
+}
@@ -0,0 +1,170 @@
+// src-tauri/src/automation/types.rs — n8n workflow bindings.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Comparator {
+    Above,
+    Below,
+}
+
+/// A threshold condition on a numeric field of the trigger event's payload,
+/// e.g. "fire when `price` goes above 25500".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Threshold {
+    pub field: String,
+    pub comparator: Comparator,
+    pub value: f64,
+}
+
+/// A bundled n8n workflow, wired to an app event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub name: String,
+    pub webhook_url: String,
+    /// The app event that fires this workflow, e.g. `"trade-price"` or
+    /// `"research-end"`.
+    pub trigger_event: String,
+    /// Template merged with the event payload before POSTing to `webhook_url`.
+    #[serde(default)]
+    pub payload_template: serde_json::Value,
+    /// When set, the workflow only fires the moment `trigger_event`'s payload
+    /// crosses this threshold (not on every occurrence of the event) — e.g.
+    /// `trade-price` ticks constantly, but a workflow bound to it with a
+    /// threshold only runs when `price` crosses from below to above `value`.
+    /// `None` fires on every occurrence of `trigger_event`, as before.
+    #[serde(default)]
+    pub threshold: Option<Threshold>,
+}
+
+#[derive(Default)]
+struct StoreState {
+    workflows: Vec<Workflow>,
+    /// Last-observed above/below state per workflow name, used to detect a
+    /// threshold crossing rather than re-firing on every tick past it.
+    threshold_state: HashMap<String, bool>,
+}
+
+/// Managed state holding every workflow the user has bound to an app event.
+#[derive(Default)]
+pub struct WorkflowStore(Mutex<StoreState>);
+
+impl WorkflowStore {
+    pub fn add(&self, workflow: Workflow) {
+        self.0.lock().unwrap().workflows.push(workflow);
+    }
+
+    pub fn remove(&self, name: &str) {
+        let mut state = self.0.lock().unwrap();
+        state.workflows.retain(|w| w.name != name);
+        state.threshold_state.remove(name);
+    }
+
+    pub fn list(&self) -> Vec<Workflow> {
+        self.0.lock().unwrap().workflows.clone()
+    }
+
+    /// Workflows bound to `event` that should fire now, given `payload`:
+    /// untresholded workflows always fire; thresholded ones only fire on the
+    /// tick `payload`'s field crosses `value` onto the comparator's side.
+    pub fn due_for(&self, event: &str, payload: &serde_json::Value) -> Vec<Workflow> {
+        let mut state = self.0.lock().unwrap();
+        let candidates: Vec<Workflow> = state
+            .workflows
+            .iter()
+            .filter(|w| w.trigger_event == event)
+            .cloned()
+            .collect();
+
+        candidates
+            .into_iter()
+            .filter(|w| match &w.threshold {
+                None => true,
+                Some(threshold) => {
+                    let Some(value) = payload.get(&threshold.field).and_then(|v| v.as_f64())
+                    else {
+                        return false;
+                    };
+                    let now_above = match threshold.comparator {
+                        Comparator::Above => value > threshold.value,
+                        Comparator::Below => value < threshold.value,
+                    };
+                    let was_above = state.threshold_state.insert(w.name.clone(), now_above);
+                    now_above && was_above == Some(false)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholded(price: f64) -> Workflow {
+        Workflow {
+            name: "alert".to_string(),
+            webhook_url: "http://localhost/hook".to_string(),
+            trigger_event: "trade-price".to_string(),
+            payload_template: serde_json::Value::Null,
+            threshold: Some(Threshold {
+                field: "price".to_string(),
+                comparator: Comparator::Above,
+                value: price,
+            }),
+        }
+    }
+
+    fn tick(price: f64) -> serde_json::Value {
+        serde_json::json!({ "price": price })
+    }
+
+    #[test]
+    fn untresholded_workflow_fires_every_time() {
+        let store = WorkflowStore::default();
+        store.add(Workflow {
+            name: "always".to_string(),
+            webhook_url: "http://localhost/hook".to_string(),
+            trigger_event: "research-end".to_string(),
+            payload_template: serde_json::Value::Null,
+            threshold: None,
+        });
+        assert_eq!(store.due_for("research-end", &serde_json::Value::Null).len(), 1);
+        assert_eq!(store.due_for("research-end", &serde_json::Value::Null).len(), 1);
+    }
+
+    #[test]
+    fn first_observation_never_fires() {
+        let store = WorkflowStore::default();
+        store.add(thresholded(100.0));
+        assert!(store.due_for("trade-price", &tick(150.0)).is_empty());
+    }
+
+    #[test]
+    fn repeated_same_side_ticks_do_not_refire() {
+        let store = WorkflowStore::default();
+        store.add(thresholded(100.0));
+        store.due_for("trade-price", &tick(150.0)); // establish baseline: above
+        assert!(store.due_for("trade-price", &tick(160.0)).is_empty());
+        assert!(store.due_for("trade-price", &tick(170.0)).is_empty());
+    }
+
+    #[test]
+    fn crossing_fires_exactly_once() {
+        let store = WorkflowStore::default();
+        store.add(thresholded(100.0));
+        store.due_for("trade-price", &tick(90.0)); // establish baseline: below
+        assert_eq!(store.due_for("trade-price", &tick(110.0)).len(), 1); // crosses above
+        assert!(store.due_for("trade-price", &tick(120.0)).is_empty()); // still above
+        assert!(store.due_for("trade-price", &tick(80.0)).is_empty()); // crosses back below
+    }
+
+    #[test]
+    fn missing_field_never_fires() {
+        let store = WorkflowStore::default();
+        store.add(thresholded(100.0));
+        assert!(store.due_for("trade-price", &serde_json::json!({})).is_empty());
+    }
+}
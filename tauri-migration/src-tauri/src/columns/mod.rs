@@ -0,0 +1,110 @@
+// src-tauri/src/columns/mod.rs — tiled multi-column child webview layout.
+//
+// The app used to have a single `main` window and smuggle embedded content
+// through the `iframe_invoke` emit hack. This adds real child
+// `WebviewWindow`s arranged as horizontal columns inside the main window:
+// the frontend creates/moves/resizes/closes columns, and since child
+// webviews don't follow DOM scroll or resize on their own, `reflow_columns`
+// recomputes and applies each one's logical position/size whenever the
+// container scrolls or resizes.
+
+mod types;
+
+pub use types::*;
+
+use tauri::{AppHandle, LogicalPosition, LogicalSize, Manager, WebviewUrl, WebviewWindowBuilder};
+
+const MIN_COLUMN_WIDTH: f64 = 280.0;
+
+/// Create a new column showing `url`, appended to the right of existing columns.
+#[tauri::command]
+pub async fn create_column(
+    label: String,
+    url: String,
+    layout: tauri::State<'_, ColumnLayout>,
+    app: AppHandle,
+) -> Result<(), String> {
+    let main_window = app
+        .get_webview_window("main")
+        .ok_or("main window not found")?;
+    let webview_url = url.parse().map_err(|e| format!("invalid column url: {e}"))?;
+    WebviewWindowBuilder::new(&app, &label, WebviewUrl::External(webview_url))
+        .parent(&main_window)
+        .map_err(|e| e.to_string())?
+        .build()
+        .map_err(|e| format!("failed to create column: {e}"))?;
+
+    layout.push(label);
+    reflow(&app, &layout)
+}
+
+/// Move a column left/right in the column order, then reflow.
+#[tauri::command]
+pub async fn move_column(
+    label: String,
+    direction: Direction,
+    layout: tauri::State<'_, ColumnLayout>,
+    app: AppHandle,
+) -> Result<(), String> {
+    layout.shift(&label, direction);
+    reflow(&app, &layout)
+}
+
+#[tauri::command]
+pub async fn set_column_title(label: String, title: String, app: AppHandle) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no such column: {label}"))?;
+    window.set_title(&title).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn close_column(
+    label: String,
+    layout: tauri::State<'_, ColumnLayout>,
+    app: AppHandle,
+) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(&label) {
+        window.close().map_err(|e| e.to_string())?;
+    }
+    layout.remove(&label);
+    reflow(&app, &layout)
+}
+
+/// Called by the frontend on the container's scroll/resize events to
+/// recompute and apply each column's logical bounds, since child webviews
+/// do not follow DOM scroll automatically.
+#[tauri::command]
+pub async fn reflow_columns(
+    viewport: Viewport,
+    layout: tauri::State<'_, ColumnLayout>,
+    app: AppHandle,
+) -> Result<(), String> {
+    layout.set_viewport(viewport);
+    reflow(&app, &layout)
+}
+
+fn reflow(app: &AppHandle, layout: &ColumnLayout) -> Result<(), String> {
+    let labels = layout.order();
+    let viewport = layout.viewport();
+    if labels.is_empty() {
+        return Ok(());
+    }
+
+    let count = labels.len() as f64;
+    let column_width = (viewport.width / count).max(MIN_COLUMN_WIDTH);
+
+    for (index, label) in labels.iter().enumerate() {
+        let Some(window) = app.get_webview_window(label) else {
+            continue;
+        };
+        let x = viewport.scroll_x + index as f64 * column_width;
+        window
+            .set_position(LogicalPosition::new(x, 0.0))
+            .map_err(|e| e.to_string())?;
+        window
+            .set_size(LogicalSize::new(column_width, viewport.height))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
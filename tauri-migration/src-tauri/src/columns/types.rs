@@ -0,0 +1,117 @@
+// src-tauri/src/columns/types.rs — column ordering and viewport state.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// The container's current scroll offset and size, as reported by the
+/// frontend on scroll/resize.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Viewport {
+    pub scroll_x: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Managed state tracking column order (left to right) and the last known
+/// viewport, so `reflow` can run from either a layout command or a
+/// scroll/resize event.
+#[derive(Default)]
+pub struct ColumnLayout {
+    inner: Mutex<LayoutState>,
+}
+
+#[derive(Default)]
+struct LayoutState {
+    order: Vec<String>,
+    viewport: Viewport,
+}
+
+impl ColumnLayout {
+    pub fn push(&self, label: String) {
+        self.inner.lock().unwrap().order.push(label);
+    }
+
+    pub fn remove(&self, label: &str) {
+        self.inner.lock().unwrap().order.retain(|l| l != label);
+    }
+
+    pub fn shift(&self, label: &str, direction: Direction) {
+        let mut state = self.inner.lock().unwrap();
+        let Some(index) = state.order.iter().position(|l| l == label) else {
+            return;
+        };
+        let target = match direction {
+            Direction::Left => index.checked_sub(1),
+            Direction::Right => Some(index + 1).filter(|&i| i < state.order.len()),
+        };
+        if let Some(target) = target {
+            state.order.swap(index, target);
+        }
+    }
+
+    pub fn order(&self) -> Vec<String> {
+        self.inner.lock().unwrap().order.clone()
+    }
+
+    pub fn set_viewport(&self, viewport: Viewport) {
+        self.inner.lock().unwrap().viewport = viewport;
+    }
+
+    pub fn viewport(&self) -> Viewport {
+        self.inner.lock().unwrap().viewport
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(labels: &[&str]) -> ColumnLayout {
+        let layout = ColumnLayout::default();
+        for label in labels {
+            layout.push(label.to_string());
+        }
+        layout
+    }
+
+    #[test]
+    fn shift_left_swaps_with_previous() {
+        let layout = layout(&["a", "b", "c"]);
+        layout.shift("b", Direction::Left);
+        assert_eq!(layout.order(), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn shift_right_swaps_with_next() {
+        let layout = layout(&["a", "b", "c"]);
+        layout.shift("b", Direction::Right);
+        assert_eq!(layout.order(), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn shift_left_at_start_is_a_no_op() {
+        let layout = layout(&["a", "b", "c"]);
+        layout.shift("a", Direction::Left);
+        assert_eq!(layout.order(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn shift_right_at_end_is_a_no_op() {
+        let layout = layout(&["a", "b", "c"]);
+        layout.shift("c", Direction::Right);
+        assert_eq!(layout.order(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn shift_unknown_label_is_a_no_op() {
+        let layout = layout(&["a", "b"]);
+        layout.shift("missing", Direction::Right);
+        assert_eq!(layout.order(), vec!["a", "b"]);
+    }
+}
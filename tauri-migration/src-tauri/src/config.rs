@@ -0,0 +1,81 @@
+// src-tauri/src/config.rs — shared runtime state for the Ollama backend.
+//
+// Every command used to build its own `reqwest::Client` and hard-code the
+// Ollama host/model/temperature. Instead we keep one pooled client and a
+// mutable `OllamaConfig` in Tauri's managed state so the frontend can
+// repoint the app at a different model or a remote Ollama host without a
+// rebuild.
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaConfig {
+    pub base_url: String,
+    pub model: String,
+    pub temperature: f32,
+    pub keep_alive: String,
+}
+
+impl Default for OllamaConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://127.0.0.1:11434".to_string(),
+            model: "llama3.2:3b".to_string(),
+            temperature: 0.3,
+            keep_alive: "5m".to_string(),
+        }
+    }
+}
+
+/// The master key `main` passes to `meilisearch.exe --master-key=...` when
+/// it spawns the bundled instance; shared here so it's only hardcoded once.
+pub const DEFAULT_MEILI_MASTER_KEY: &str = "regen2026";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeiliConfig {
+    pub base_url: String,
+    pub master_key: String,
+}
+
+impl Default for MeiliConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://127.0.0.1:7700".to_string(),
+            master_key: DEFAULT_MEILI_MASTER_KEY.to_string(),
+        }
+    }
+}
+
+/// Managed via `app.manage(AppState::default())`; fetched in commands with
+/// `State<'_, AppState>`.
+pub struct AppState {
+    pub http: Client,
+    pub config: Mutex<OllamaConfig>,
+    pub meili: Mutex<MeiliConfig>,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self {
+            http: Client::new(),
+            config: Mutex::new(OllamaConfig::default()),
+            meili: Mutex::new(MeiliConfig::default()),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_config(state: tauri::State<'_, AppState>) -> OllamaConfig {
+    state.config.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_config(
+    config: OllamaConfig,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    *state.config.lock().unwrap() = config;
+    Ok(())
+}
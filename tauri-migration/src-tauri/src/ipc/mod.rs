@@ -0,0 +1,108 @@
+// src-tauri/src/ipc/mod.rs — scoped IPC gateway for embedded/remote content.
+//
+// `iframe_invoke` used to forward an arbitrary string blob via
+// `window.emit("iframe-call", ...)`, giving any embedded page an unscoped,
+// unauthenticated path into the backend. This replaces it with Tauri's
+// `RemoteDomainAccessScope`: remote/iframe origins are whitelisted in
+// `OriginAllowlist` config up front, and `remote_invoke` only dispatches to
+// the small set of commands each origin is allowed to call.
+
+mod types;
+
+pub use types::*;
+
+use crate::config::AppState;
+use crate::search;
+use tauri::{AppHandle, Manager, State, WebviewWindow};
+
+/// Grants every configured origin access to the `remote_invoke` gateway via
+/// Tauri's `RemoteDomainAccessScope`. Takes an `AppHandle` rather than
+/// `&tauri::App` so it's re-runnable after setup: called once from `main`'s
+/// `.setup()` for the startup allowlist, and again from `set_allowed_origins`
+/// whenever the allowlist changes at runtime, otherwise a newly-added origin
+/// would pass `allowlist.permits()` while still never having been granted
+/// scope to call `remote_invoke` in the first place. Origins are scoped to
+/// `remote_invoke` only, never to the underlying commands it dispatches to
+/// (`trade_api`, `search`, …) — those stay gated solely by `remote_invoke`'s
+/// own `allowlist.permits()` check, so a remote window can't reach them
+/// directly and bypass it.
+///
+/// Note Tauri has no corresponding "revoke" API: removing an origin from the
+/// allowlist stops `permits()` from authorizing new calls, but a domain that
+/// was ever granted scope keeps the ability to invoke `remote_invoke` itself.
+pub fn register_scopes(app: &AppHandle, allowlist: &OriginAllowlist) -> tauri::Result<()> {
+    for rule in allowlist.get().origins {
+        let scope = app.remote_domain_access_scope(rule.domain)?;
+        scope.allow_window("remote_invoke");
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_allowed_origins(allowlist: State<'_, OriginAllowlist>) -> AllowedOrigins {
+    allowlist.get()
+}
+
+#[tauri::command]
+pub fn set_allowed_origins(
+    origins: AllowedOrigins,
+    allowlist: State<'_, OriginAllowlist>,
+    app: AppHandle,
+) -> Result<(), String> {
+    allowlist.set(origins);
+    register_scopes(&app, &allowlist).map_err(|e| e.to_string())
+}
+
+/// Dispatches a call that arrived from an allow-listed remote/iframe origin.
+/// The origin is read from the calling window's own URL rather than trusted
+/// from the request — a self-reported `origin` field could be forged by the
+/// very embedded content the allowlist exists to contain.
+#[tauri::command]
+pub async fn remote_invoke(
+    request: RemoteRequest,
+    window: WebviewWindow,
+    allowlist: State<'_, OriginAllowlist>,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let url = window.url().map_err(|e| e.to_string())?;
+    let origin = match (url.host_str(), url.port_or_known_default()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        _ => return Err("caller window has no origin".to_string()),
+    };
+
+    if !allowlist.permits(&origin, &request.command) {
+        return Err(format!(
+            "origin {origin} is not permitted to call {}",
+            request.command
+        ));
+    }
+
+    match request.command.as_str() {
+        "trade_api" => {
+            let symbol = request
+                .payload
+                .get("symbol")
+                .and_then(|v| v.as_str())
+                .ok_or("missing `symbol` payload field")?
+                .to_string();
+            crate::trade_api(symbol, state).await
+        }
+        "search" => {
+            let query = request
+                .payload
+                .get("query")
+                .and_then(|v| v.as_str())
+                .ok_or("missing `query` payload field")?
+                .to_string();
+            let semantic_ratio = request
+                .payload
+                .get("semantic_ratio")
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.5) as f32;
+            let hits = search::search(query, semantic_ratio, state).await?;
+            serde_json::to_value(hits).map_err(|e| e.to_string())
+        }
+        other => Err(format!("command not routed through remote_invoke: {other}")),
+    }
+}
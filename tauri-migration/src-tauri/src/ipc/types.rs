@@ -0,0 +1,106 @@
+// src-tauri/src/ipc/types.rs — per-origin allowlist and request shape.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// One remote/iframe origin and the commands it may invoke, e.g.
+/// `example.com:443` reaching only `trade_api`/`search`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OriginRule {
+    pub domain: String,
+    pub commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AllowedOrigins {
+    pub origins: Vec<OriginRule>,
+}
+
+impl AllowedOrigins {
+    fn permits(&self, domain: &str, command: &str) -> bool {
+        self.origins
+            .iter()
+            .any(|rule| rule.domain == domain && rule.commands.iter().any(|c| c == command))
+    }
+
+    /// No origin is trusted out of the box — `127.0.0.1:11434` is the
+    /// Ollama HTTP API, not a webview origin `create_column` ever loads, so
+    /// there's no real default to ship. Whoever adds a remote/iframe column
+    /// must explicitly grant it via `set_allowed_origins` first.
+    fn defaults() -> Self {
+        Self { origins: Vec::new() }
+    }
+}
+
+/// Managed via `app.manage(OriginAllowlist::default())`; holds the allowlist
+/// behind a `Mutex` so `set_allowed_origins` can update it at runtime, the
+/// same pattern as `AppState`'s `OllamaConfig`.
+pub struct OriginAllowlist(Mutex<AllowedOrigins>);
+
+impl Default for OriginAllowlist {
+    fn default() -> Self {
+        Self(Mutex::new(AllowedOrigins::defaults()))
+    }
+}
+
+impl OriginAllowlist {
+    pub fn permits(&self, domain: &str, command: &str) -> bool {
+        self.0.lock().unwrap().permits(domain, command)
+    }
+
+    pub fn get(&self) -> AllowedOrigins {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub fn set(&self, origins: AllowedOrigins) {
+        *self.0.lock().unwrap() = origins;
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoteRequest {
+    pub command: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(domain: &str, commands: &[&str]) -> OriginRule {
+        OriginRule {
+            domain: domain.to_string(),
+            commands: commands.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn permits_matching_domain_and_command() {
+        let origins = AllowedOrigins {
+            origins: vec![rule("example.com", &["search"])],
+        };
+        assert!(origins.permits("example.com", "search"));
+    }
+
+    #[test]
+    fn denies_right_domain_wrong_command() {
+        let origins = AllowedOrigins {
+            origins: vec![rule("example.com", &["search"])],
+        };
+        assert!(!origins.permits("example.com", "trade_api"));
+    }
+
+    #[test]
+    fn denies_unlisted_domain() {
+        let origins = AllowedOrigins {
+            origins: vec![rule("example.com", &["search"])],
+        };
+        assert!(!origins.permits("evil.com", "search"));
+    }
+
+    #[test]
+    fn default_allowlist_is_empty() {
+        assert!(AllowedOrigins::defaults().origins.is_empty());
+    }
+}
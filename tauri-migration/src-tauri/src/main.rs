@@ -1,109 +1,92 @@
 // src-tauri/src/main.rs — FINAL WORKING BACKEND (100% GUARANTEED)
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::{Manager, WebviewWindow};
+mod automation;
+mod columns;
+mod config;
+mod ipc;
+mod ollama;
+mod search;
+mod social;
+
+use automation::{
+    fire_bound_workflows, list_workflows, register_workflow, trigger_workflow,
+    unregister_workflow, WorkflowStore,
+};
+use columns::{close_column, create_column, move_column, reflow_columns, set_column_title, ColumnLayout};
+use config::{get_config, set_config, AppState};
+use ipc::{get_allowed_origins, remote_invoke, set_allowed_origins, OriginAllowlist};
+use ollama::{chat, generate, list_models, StreamEvents};
+use search::{configure_embedder, configure_facets, index_document, search};
+use social::{publish_feed_item, FeedStore};
+use tauri::{Listener, Manager, State, WebviewWindow};
 use std::process::Command;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
-use reqwest::Client;
 use serde_json::{json, Value};
-use futures_util::StreamExt;
+
+const RESEARCH_EVENTS: StreamEvents = StreamEvents {
+    token: "research-token",
+    end: "research-end",
+};
+
+const TRADE_EVENTS: StreamEvents = StreamEvents {
+    token: "trade-token",
+    end: "trade-stream-end",
+};
 
 #[tauri::command]
-async fn research_stream(query: String, window: WebviewWindow) -> Result<(), String> {
-    let client = Client::new();
+async fn research_stream(
+    query: String,
+    window: WebviewWindow,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let config = state.config.lock().unwrap().clone();
+    let client = ollama::Client::new(state.http.clone(), config);
     let prompt = format!("You are Regen — India's offline AI browser. Answer in the user's language. Query: {query}");
 
-    let res = client.post("http://127.0.0.1:11434/api/generate")
-        .json(&json!({
-            "model": "llama3.2:3b",
-            "prompt": prompt,
-            "stream": true,
-            "options": { "temperature": 0.3 }
-        }))
-        .send().await;
-
-    if let Ok(res) = res {
-        if res.status().is_success() {
-            let mut stream = res.bytes_stream();
-            window.emit("research-start", query.clone()).ok();
-
-            while let Some(chunk) = stream.next().await {
-                if let Ok(bytes) = chunk {
-                    if let Ok(text) = std::str::from_utf8(&bytes) {
-                        for line in text.lines() {
-                            if line.trim().is_empty() { continue; }
-                            if let Ok(json) = serde_json::from_str::<Value>(line) {
-                                if json["done"] == true { 
-                                    window.emit("research-end", ()).ok();
-                                    return Ok(());
-                                }
-                                if let Some(token) = json["response"].as_str() {
-                                    window.emit("research-token", token).ok();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    Err("Ollama not responding".to_string())
+    window.emit("research-start", query.clone()).ok();
+    client
+        .generate_stream(&prompt, &window, RESEARCH_EVENTS)
+        .await
+        .map_err(Into::into)
 }
 
 #[tauri::command]
-async fn trade_stream(symbol: String, window: WebviewWindow) -> Result<(), String> {
+async fn trade_stream(
+    symbol: String,
+    window: WebviewWindow,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
     // Live price
-    let client = Client::new();
+    let http = state.http.clone();
+    let config = state.config.lock().unwrap().clone();
     let yahoo = if symbol == "NIFTY" { "^NSEI" } else { "^NSEBANK" };
-    let price_res = client.get(&format!("https://query1.finance.yahoo.com/v8/finance/chart/{}", yahoo))
+    let price_res = http.get(&format!("https://query1.finance.yahoo.com/v8/finance/chart/{}", yahoo))
         .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
         .query(&[("interval", "1m"), ("range", "1d")])
         .send().await.ok()
         .and_then(|r| r.json::<Value>().await.ok());
 
-    let price = price_res.and_then(|j| j["chart"]["result"][0]["meta"]["regularMarketPrice"].as_f64()).unwrap_or(25000.0);
+    let price = price_res.as_ref().and_then(|j| j["chart"]["result"][0]["meta"]["regularMarketPrice"].as_f64()).unwrap_or(25000.0);
     let change = price_res.and_then(|j| j["chart"]["result"][0]["meta"]["regularMarketChangePercent"].as_f64()).unwrap_or(0.0);
 
     window.emit("trade-price", json!({ "price": price, "change": change })).ok();
 
     // AI signal
     let prompt = format!("Current {symbol}: ₹{price:.2} ({change:+.2}%). Give Hindi/English trading signal: BUY/SELL/HOLD + target + stoploss");
-    let res = client.post("http://127.0.0.1:11434/api/generate")
-        .json(&json!({ "model": "llama3.2:3b", "prompt": prompt, "stream": true }))
-        .send().await;
-
-    if let Ok(res) = res {
-        if res.status().is_success() {
-            let mut stream = res.bytes_stream();
-            window.emit("trade-stream-start", symbol.clone()).ok();
-
-            while let Some(chunk) = stream.next().await {
-                if let Ok(bytes) = chunk {
-                    if let Ok(text) = std::str::from_utf8(&bytes) {
-                        for line in text.lines() {
-                            if line.trim().is_empty() { continue; }
-                            if let Ok(json) = serde_json::from_str::<Value>(line) {
-                                if json["done"] == true { 
-                                    window.emit("trade-stream-end", ()).ok();
-                                    return Ok(());
-                                }
-                                if let Some(token) = json["response"].as_str() {
-                                    window.emit("trade-token", token).ok();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let client = ollama::Client::new(http, config);
+    window.emit("trade-stream-start", symbol.clone()).ok();
+    // Best-effort: the live price already went out above, so a flaky model
+    // shouldn't fail the whole command the way it does for research_stream.
+    let _ = client.generate_stream(&prompt, &window, TRADE_EVENTS).await;
     Ok(())
 }
 
 #[tauri::command]
-async fn trade_api(symbol: String) -> Result<Value, String> {
-    let client = Client::new();
+pub(crate) async fn trade_api(symbol: String, state: State<'_, AppState>) -> Result<Value, String> {
+    let client = state.http.clone();
     let yahoo = if symbol == "NIFTY" { "^NSEI" } else { "^NSEBANK" };
     let res = client
         .get(&format!("https://query1.finance.yahoo.com/v8/finance/chart/{}", yahoo))
@@ -117,25 +100,43 @@ async fn trade_api(symbol: String) -> Result<Value, String> {
         .map_err(|e| format!("JSON parse failed: {}", e))
 }
 
-#[tauri::command]
-fn iframe_invoke(shim: String, window: WebviewWindow) -> Result<(), String> {
-    // Forward invoke from iframe to main window (fixes #6204)
-    window
-        .emit("iframe-call", shim)
-        .map_err(|e| format!("Emit failed: {}", e))
-}
-
 #[cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 fn main() {
     tauri::Builder::default()
+        .manage(AppState::default())
+        .manage(ColumnLayout::default())
+        .manage(OriginAllowlist::default())
+        .manage(Arc::new(FeedStore::default()))
+        .manage(WorkflowStore::default())
         .setup(|app| {
             // Fix OLLAMA_ORIGIN for Tauri (allows localhost:11434 from webview)
             std::env::set_var("OLLAMA_ORIGINS", "*"); // Temp dev; restrict prod to "tauri://localhost"
             std::env::set_var("OLLAMA_HOST", "0.0.0.0:11434"); // Bind all interfaces
             std::env::set_var("OLLAMA_ALLOW_PRIVATE_NETWORK", "true");
 
+            let allowlist = app.state::<OriginAllowlist>();
+            ipc::register_scopes(&app.handle(), &allowlist)?;
+
+            let feed_store = app.state::<Arc<FeedStore>>();
+            social::serve_feed_generator(feed_store.inner().clone());
+
             let window = app.get_webview_window("main").unwrap();
 
+            // Auto-run any workflow bound to `trade-price` / `research-end`.
+            for event in ["trade-price", "research-end"] {
+                let app_handle = app.handle().clone();
+                window.listen(event, move |e| {
+                    let app_handle = app_handle.clone();
+                    let payload: Value = serde_json::from_str(e.payload()).unwrap_or(Value::Null);
+                    tauri::async_runtime::spawn(async move {
+                        let state = app_handle.state::<AppState>();
+                        let store = app_handle.state::<WorkflowStore>();
+                        let window = app_handle.get_webview_window("main").unwrap();
+                        fire_bound_workflows(&state.http, &window, &store, event, payload).await;
+                    });
+                });
+            }
+
             // AUTO START EVERYTHING
             #[cfg(target_os = "windows")]
             {
@@ -160,13 +161,25 @@ fn main() {
                         sleep(Duration::from_secs(3)).await;
                     }
 
-                    // Try to pull model (non-blocking)
-                    let _ = Command::new("ollama")
-                        .args(["pull", "llama3.2:3b"])
-                        .spawn();
+                    // Pull the configured model, streaming progress to the
+                    // window instead of the old fire-and-forget subprocess.
+                    let state = window_clone.state::<AppState>();
+                    let config = state.config.lock().unwrap().clone();
+                    let model = config.model.clone();
+                    let client = ollama::Client::new(state.http.clone(), config);
+                    if let Err(e) = client.pull(&model, &window_clone).await {
+                        eprintln!("model pull failed: {e}");
+                    }
 
                     window_clone.emit("ollama-ready", ()).ok();
                     window_clone.emit("backend-ready", ()).ok();
+
+                    // One-time MeiliSearch index setup so the `_vectors`
+                    // field `index_document`/`search` rely on is accepted.
+                    let state = window_clone.state::<AppState>();
+                    if let Err(e) = search::configure_embedder(state).await {
+                        eprintln!("MeiliSearch embedder setup failed: {e}");
+                    }
                 });
 
                 // Try to start MeiliSearch and n8n from bin if available
@@ -175,7 +188,10 @@ fn main() {
                     if bin_path.exists() {
                         // MeiliSearch
                         let _ = Command::new("cmd")
-                            .args(["/C", "start", "/B", "meilisearch.exe", "--master-key=regen2026"])
+                            .args([
+                                "/C", "start", "/B", "meilisearch.exe",
+                                &format!("--master-key={}", config::DEFAULT_MEILI_MASTER_KEY),
+                            ])
                             .current_dir(&bin_path)
                             .spawn();
 
@@ -189,7 +205,10 @@ fn main() {
 
                 // Also try MeiliSearch from PATH if bin doesn't exist
                 let _ = Command::new("cmd")
-                    .args(["/C", "start", "/B", "meilisearch", "--master-key=regen2026"])
+                    .args([
+                        "/C", "start", "/B", "meilisearch",
+                        &format!("--master-key={}", config::DEFAULT_MEILI_MASTER_KEY),
+                    ])
                     .spawn();
             }
 
@@ -199,7 +218,28 @@ fn main() {
             research_stream,
             trade_stream,
             trade_api,
-            iframe_invoke
+            generate,
+            chat,
+            list_models,
+            get_config,
+            set_config,
+            index_document,
+            search,
+            configure_facets,
+            configure_embedder,
+            create_column,
+            move_column,
+            set_column_title,
+            close_column,
+            reflow_columns,
+            get_allowed_origins,
+            set_allowed_origins,
+            remote_invoke,
+            publish_feed_item,
+            register_workflow,
+            unregister_workflow,
+            list_workflows,
+            trigger_workflow
         ])
         .run(tauri::generate_context!())
         .expect("error while running Regen");
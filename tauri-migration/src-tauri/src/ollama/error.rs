@@ -0,0 +1,55 @@
+// src-tauri/src/ollama/error.rs — typed errors for the Ollama client.
+//
+// Previously a failed request just surfaced as `Err("Ollama not responding")`
+// regardless of cause. This distinguishes transport failures from Ollama's
+// own error payloads so callers (and the frontend) can tell them apart.
+
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Debug, Deserialize)]
+struct OllamaErrorBody {
+    error: String,
+}
+
+#[derive(Debug)]
+pub enum OllamaError {
+    /// The request never made it to Ollama (connection refused, timeout, …).
+    Request(reqwest::Error),
+    /// Ollama responded with a non-2xx status and an `{"error": "..."}` body.
+    Api { status: u16, message: String },
+    /// The response body wasn't valid UTF-8.
+    InvalidUtf8,
+    /// The response body didn't match the expected shape.
+    Decode(serde_json::Error),
+}
+
+impl OllamaError {
+    pub(super) async fn from_status(res: reqwest::Response) -> Self {
+        let status = res.status().as_u16();
+        let message = match res.json::<OllamaErrorBody>().await {
+            Ok(body) => body.error,
+            Err(_) => format!("Ollama returned status {status}"),
+        };
+        OllamaError::Api { status, message }
+    }
+}
+
+impl fmt::Display for OllamaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OllamaError::Request(e) => write!(f, "Ollama not responding: {e}"),
+            OllamaError::Api { status, message } => write!(f, "Ollama error ({status}): {message}"),
+            OllamaError::InvalidUtf8 => write!(f, "Ollama returned non-UTF-8 output"),
+            OllamaError::Decode(e) => write!(f, "failed to decode Ollama response: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for OllamaError {}
+
+impl From<OllamaError> for String {
+    fn from(e: OllamaError) -> Self {
+        e.to_string()
+    }
+}
@@ -0,0 +1,229 @@
+// src-tauri/src/ollama/mod.rs — typed Ollama client.
+//
+// Replaces the ad-hoc `serde_json::json!` bodies and manual line-splitting
+// that used to live inline in each Tauri command. `Client` wraps a pooled
+// `reqwest::Client` plus an `OllamaConfig` and exposes typed methods for
+// every endpoint the app talks to: `generate`, `chat`, `embeddings`,
+// `list`, and `pull`.
+
+mod error;
+mod types;
+
+pub use error::OllamaError;
+pub use types::*;
+
+use crate::config::{AppState, OllamaConfig};
+use futures_util::StreamExt;
+use tauri::{State, Window};
+
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    config: OllamaConfig,
+}
+
+impl Client {
+    pub fn new(http: reqwest::Client, config: OllamaConfig) -> Self {
+        Self { http, config }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url, path)
+    }
+
+    /// POST /api/generate (non-streaming; callers that need token-by-token
+    /// output use `generate_stream`, as `research_stream` and `trade_stream`
+    /// do, since those need to forward events to a window).
+    pub async fn generate(&self, prompt: &str) -> Result<GenerateResponse, OllamaError> {
+        let res = self
+            .http
+            .post(self.url("/api/generate"))
+            .json(&json_generate_request(&self.config, prompt, false))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+        parse_response(res).await
+    }
+
+    /// POST /api/chat with a multi-turn `messages` array.
+    pub async fn chat(&self, messages: &[ChatMessage]) -> Result<ChatResponse, OllamaError> {
+        let res = self
+            .http
+            .post(self.url("/api/chat"))
+            .json(&serde_json::json!({
+                "model": self.config.model,
+                "messages": messages,
+                "stream": false,
+                "keep_alive": self.config.keep_alive,
+                "options": { "temperature": self.config.temperature },
+            }))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+        parse_response(res).await
+    }
+
+    /// POST /api/embeddings.
+    pub async fn embeddings(&self, model: &str, input: &str) -> Result<EmbeddingsResponse, OllamaError> {
+        let res = self
+            .http
+            .post(self.url("/api/embeddings"))
+            .json(&serde_json::json!({ "model": model, "prompt": input }))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+        parse_response(res).await
+    }
+
+    /// GET /api/tags — installed models.
+    pub async fn list(&self) -> Result<ListResponse, OllamaError> {
+        let res = self
+            .http
+            .get(self.url("/api/tags"))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+        parse_response(res).await
+    }
+
+    /// POST /api/generate with `stream: true`, forwarding each token to the
+    /// window as it arrives. `events` names the token/end events to emit,
+    /// since `research_stream` and `trade_stream` each use their own event
+    /// names for the same underlying generate-and-stream behavior. Callers
+    /// emit their own "start" event first, since its payload (the query, the
+    /// symbol, …) differs per caller.
+    pub async fn generate_stream(
+        &self,
+        prompt: &str,
+        window: &Window,
+        events: StreamEvents,
+    ) -> Result<(), OllamaError> {
+        let res = self
+            .http
+            .post(self.url("/api/generate"))
+            .json(&json_generate_request(&self.config, prompt, true))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+
+        if !res.status().is_success() {
+            return Err(OllamaError::from_status(res).await);
+        }
+
+        let mut stream = res.bytes_stream();
+
+        while let Some(chunk) = stream.next().await {
+            let bytes = chunk.map_err(OllamaError::Request)?;
+            let text = std::str::from_utf8(&bytes).map_err(|_| OllamaError::InvalidUtf8)?;
+            for line in text.lines() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let piece: GenerateResponse =
+                    serde_json::from_str(line).map_err(OllamaError::Decode)?;
+                if piece.done {
+                    window.emit(events.end, ()).ok();
+                    return Ok(());
+                }
+                window.emit(events.token, piece.response).ok();
+            }
+        }
+        window.emit(events.end, ()).ok();
+        Ok(())
+    }
+
+    /// POST /api/pull, consuming the NDJSON progress stream and emitting a
+    /// `model-pull-progress` event per line so the UI can render a real
+    /// download bar instead of the old fire-and-forget `ollama pull` subprocess.
+    pub async fn pull(&self, model: &str, window: &Window) -> Result<(), OllamaError> {
+        let res = self
+            .http
+            .post(self.url("/api/pull"))
+            .json(&serde_json::json!({ "model": model, "stream": true }))
+            .send()
+            .await
+            .map_err(OllamaError::Request)?;
+
+        if !res.status().is_success() {
+            return Err(OllamaError::from_status(res).await);
+        }
+
+        let mut stream = res.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let bytes = chunk.map_err(OllamaError::Request)?;
+            let text = std::str::from_utf8(&bytes).map_err(|_| OllamaError::InvalidUtf8)?;
+            for line in text.lines() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let progress: PullProgress =
+                    serde_json::from_str(line).map_err(OllamaError::Decode)?;
+                window.emit("model-pull-progress", &progress).ok();
+                if progress.status == "success" {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One-shot, non-streaming completion — for callers that just want the final
+/// text (e.g. suggesting a title for a column) rather than the token-by-token
+/// events `research_stream`/`trade_stream` forward to a window.
+#[tauri::command]
+pub async fn generate(prompt: String, state: State<'_, AppState>) -> Result<GenerateResponse, String> {
+    let config = state.config.lock().unwrap().clone();
+    Client::new(state.http.clone(), config)
+        .generate(&prompt)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// One-shot multi-turn chat — a full `messages` round-trip, for UI surfaces
+/// that want a conversation rather than a single streamed prompt.
+#[tauri::command]
+pub async fn chat(messages: Vec<ChatMessage>, state: State<'_, AppState>) -> Result<ChatResponse, String> {
+    let config = state.config.lock().unwrap().clone();
+    Client::new(state.http.clone(), config)
+        .chat(&messages)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Installed models (`ollama list`), for a model picker in settings.
+#[tauri::command]
+pub async fn list_models(state: State<'_, AppState>) -> Result<ListResponse, String> {
+    let config = state.config.lock().unwrap().clone();
+    Client::new(state.http.clone(), config)
+        .list()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Event names used by [`Client::generate_stream`].
+#[derive(Clone, Copy)]
+pub struct StreamEvents {
+    pub token: &'static str,
+    pub end: &'static str,
+}
+
+fn json_generate_request(config: &OllamaConfig, prompt: &str, stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": config.model,
+        "prompt": prompt,
+        "stream": stream,
+        "keep_alive": config.keep_alive,
+        "options": { "temperature": config.temperature },
+    })
+}
+
+async fn parse_response<T: serde::de::DeserializeOwned>(
+    res: reqwest::Response,
+) -> Result<T, OllamaError> {
+    if !res.status().is_success() {
+        return Err(OllamaError::from_status(res).await);
+    }
+    let bytes = res.bytes().await.map_err(OllamaError::Request)?;
+    serde_json::from_slice(&bytes).map_err(OllamaError::Decode)
+}
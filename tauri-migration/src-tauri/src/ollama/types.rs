@@ -0,0 +1,50 @@
+// src-tauri/src/ollama/types.rs — request/response shapes for the Ollama HTTP API.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateResponse {
+    pub model: String,
+    pub response: String,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatResponse {
+    pub model: String,
+    pub message: ChatMessage,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmbeddingsResponse {
+    pub embedding: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    pub size: u64,
+    pub digest: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListResponse {
+    pub models: Vec<ModelInfo>,
+}
+
+/// One line of the NDJSON stream returned by `POST /api/pull`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullProgress {
+    pub status: String,
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub completed: Option<u64>,
+}
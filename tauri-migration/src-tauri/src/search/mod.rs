@@ -0,0 +1,159 @@
+// src-tauri/src/search/mod.rs — semantic history/research index.
+//
+// `main` already launches `meilisearch.exe --master-key=regen2026` but
+// nothing indexed or queried it. This module turns it into an actual recall
+// layer: every research query/answer and visited page gets indexed with a
+// keyword field plus an embedding vector (via `ollama::Client::embeddings`,
+// model `nomic-embed-text`) stored in MeiliSearch's `_vectors` field, and
+// `search` issues a hybrid keyword + vector query against it.
+
+mod types;
+
+pub use types::*;
+
+use crate::config::AppState;
+use crate::ollama;
+use tauri::State;
+
+const INDEX: &str = "history";
+const EMBEDDER: &str = "default";
+const EMBEDDING_MODEL: &str = "nomic-embed-text";
+/// Output size of `nomic-embed-text`'s embedding vectors.
+const EMBEDDING_DIMENSIONS: u32 = 768;
+
+fn meili_url(config: &crate::config::MeiliConfig, path: &str) -> String {
+    format!("{}{}", config.base_url, path)
+}
+
+/// Index one document (a research Q&A pair or a visited page) with its
+/// embedding vector.
+#[tauri::command]
+pub async fn index_document(
+    doc: IndexableDocument,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let ollama_config = state.config.lock().unwrap().clone();
+    let meili_config = state.meili.lock().unwrap().clone();
+    let ollama = ollama::Client::new(state.http.clone(), ollama_config);
+
+    let embedding = ollama
+        .embeddings(EMBEDDING_MODEL, &doc.text())
+        .await
+        .map_err(|e| e.to_string())?
+        .embedding;
+
+    let payload = serde_json::json!({
+        "id": doc.id,
+        "source": doc.source,
+        "title": doc.title,
+        "body": doc.body,
+        "date": doc.date,
+        "_vectors": { EMBEDDER: embedding },
+    });
+
+    let res = state
+        .http
+        .post(meili_url(&meili_config, &format!("/indexes/{INDEX}/documents")))
+        .bearer_auth(&meili_config.master_key)
+        .json(&[payload])
+        .send()
+        .await
+        .map_err(|e| format!("MeiliSearch request failed: {e}"))?;
+
+    if !res.status().is_success() {
+        return Err(format!("MeiliSearch returned status {}", res.status()));
+    }
+    Ok(())
+}
+
+/// Hybrid keyword + vector search. `semantic_ratio` (0.0-1.0) tunes how much
+/// weight the embedding similarity gets versus BM25 keyword relevance.
+#[tauri::command]
+pub async fn search(
+    query: String,
+    semantic_ratio: f32,
+    state: State<'_, AppState>,
+) -> Result<SearchResults, String> {
+    let ollama_config = state.config.lock().unwrap().clone();
+    let meili_config = state.meili.lock().unwrap().clone();
+    let ollama = ollama::Client::new(state.http.clone(), ollama_config);
+
+    let embedding = ollama
+        .embeddings(EMBEDDING_MODEL, &query)
+        .await
+        .map_err(|e| e.to_string())?
+        .embedding;
+
+    let res = state
+        .http
+        .post(meili_url(&meili_config, &format!("/indexes/{INDEX}/search")))
+        .bearer_auth(&meili_config.master_key)
+        .json(&serde_json::json!({
+            "q": query,
+            "hybrid": { "embedder": EMBEDDER, "semanticRatio": semantic_ratio },
+            "vector": embedding,
+            "attributesToHighlight": ["title", "body"],
+            "facets": ["source", "date"],
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("MeiliSearch request failed: {e}"))?;
+
+    if !res.status().is_success() {
+        return Err(format!("MeiliSearch returned status {}", res.status()));
+    }
+    res.json::<SearchResults>()
+        .await
+        .map_err(|e| format!("failed to parse MeiliSearch response: {e}"))
+}
+
+/// One-time setup: registers the `default` embedder on the index as
+/// `userProvided`, since we compute embeddings ourselves via Ollama rather
+/// than having MeiliSearch generate them. Without this, the `_vectors` field
+/// written by `index_document` and the `hybrid`/`vector` search in `search`
+/// are both rejected by MeiliSearch. Safe to call repeatedly (idempotent) —
+/// call it once after MeiliSearch starts, e.g. from `main`'s startup sequence.
+#[tauri::command]
+pub async fn configure_embedder(state: State<'_, AppState>) -> Result<(), String> {
+    let meili_config = state.meili.lock().unwrap().clone();
+    let res = state
+        .http
+        .patch(meili_url(&meili_config, &format!("/indexes/{INDEX}/settings/embedders")))
+        .bearer_auth(&meili_config.master_key)
+        .json(&serde_json::json!({
+            EMBEDDER: { "source": "userProvided", "dimensions": EMBEDDING_DIMENSIONS }
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("MeiliSearch request failed: {e}"))?;
+
+    if !res.status().is_success() {
+        return Err(format!("MeiliSearch returned status {}", res.status()));
+    }
+    Ok(())
+}
+
+/// Configure which attributes can be filtered/faceted on (`source`, `date`, …).
+#[tauri::command]
+pub async fn configure_facets(
+    attributes: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let meili_config = state.meili.lock().unwrap().clone();
+    let res = state
+        .http
+        .put(meili_url(
+            &meili_config,
+            &format!("/indexes/{INDEX}/settings/filterable-attributes"),
+        ))
+        .bearer_auth(&meili_config.master_key)
+        .json(&attributes)
+        .send()
+        .await
+        .map_err(|e| format!("MeiliSearch request failed: {e}"))?;
+
+    if !res.status().is_success() {
+        return Err(format!("MeiliSearch returned status {}", res.status()));
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// src-tauri/src/search/types.rs — documents and results for the history index.
+
+use serde::{Deserialize, Serialize};
+
+/// A research Q&A pair or a visited page, as handed to `index_document`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexableDocument {
+    pub id: String,
+    /// e.g. "research", "trade", "page"
+    pub source: String,
+    pub title: String,
+    pub body: String,
+    /// Unix timestamp, for the `date` facet.
+    pub date: i64,
+}
+
+impl IndexableDocument {
+    /// The text embedded for the `_vectors` field.
+    pub fn text(&self) -> String {
+        format!("{}\n{}", self.title, self.body)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchHit {
+    pub id: String,
+    pub source: String,
+    pub title: String,
+    pub body: String,
+    pub date: i64,
+    #[serde(rename = "_formatted", default)]
+    pub highlight: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    #[serde(rename = "estimatedTotalHits", default)]
+    pub estimated_total_hits: u64,
+    #[serde(rename = "facetDistribution", default)]
+    pub facet_distribution: Option<serde_json::Value>,
+}
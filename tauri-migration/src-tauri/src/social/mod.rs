@@ -0,0 +1,62 @@
+// src-tauri/src/social/mod.rs — AT Protocol custom feed generator.
+//
+// Serves the `app.bsky.feed.generator` `getFeedSkeleton` XRPC method so a
+// user's saved research sessions and trade signals can be published as a
+// subscribable Bluesky feed. The skeleton is assembled from whatever items
+// were recorded via `publish_feed_item`, newest first, with cursor-based
+// pagination; the cursor is an offset counted from the newest item.
+
+mod server;
+mod types;
+
+pub use types::*;
+
+use std::sync::Arc;
+use tauri::State;
+
+/// Managed state holding every item the user has chosen to publish, in the
+/// order `publish_feed_item` recorded them.
+#[derive(Default)]
+pub struct FeedStore(std::sync::Mutex<Vec<FeedItem>>);
+
+impl FeedStore {
+    pub fn push(&self, item: FeedItem) {
+        self.0.lock().unwrap().push(item);
+    }
+
+    pub fn skeleton(&self, cursor: Option<usize>, limit: usize) -> FeedSkeleton {
+        let items = self.0.lock().unwrap();
+        let start = cursor.unwrap_or(0);
+        let page: Vec<FeedSkeletonItem> = items
+            .iter()
+            .rev()
+            .skip(start)
+            .take(limit)
+            .map(|item| FeedSkeletonItem {
+                post: item.at_uri.clone(),
+            })
+            .collect();
+        let next_cursor = if start + page.len() < items.len() {
+            Some((start + page.len()).to_string())
+        } else {
+            None
+        };
+        FeedSkeleton {
+            feed: page,
+            cursor: next_cursor,
+        }
+    }
+}
+
+/// Records an AT-URI for a research session or trade signal the user chose
+/// to share, so it shows up in the next `getFeedSkeleton` response.
+#[tauri::command]
+pub fn publish_feed_item(at_uri: String, store: State<'_, Arc<FeedStore>>) -> Result<(), String> {
+    if !at_uri.starts_with("at://") {
+        return Err("expected an at:// URI".to_string());
+    }
+    store.push(FeedItem { at_uri });
+    Ok(())
+}
+
+pub use server::serve_feed_generator;
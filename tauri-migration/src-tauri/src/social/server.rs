@@ -0,0 +1,37 @@
+// src-tauri/src/social/server.rs — the embedded getFeedSkeleton HTTP route.
+
+use super::{FeedSkeletonQuery, FeedStore};
+use axum::{extract::Query, extract::State as AxumState, routing::get, Json, Router};
+use std::sync::Arc;
+
+const FEED_GENERATOR_ADDR: &str = "127.0.0.1:8787";
+
+async fn get_feed_skeleton(
+    AxumState(store): AxumState<Arc<FeedStore>>,
+    Query(query): Query<FeedSkeletonQuery>,
+) -> Json<super::FeedSkeleton> {
+    let cursor = query.cursor.and_then(|c| c.parse::<usize>().ok());
+    Json(store.skeleton(cursor, query.limit))
+}
+
+/// Spawns the `app.bsky.feed.generator` XRPC endpoint on a background task.
+/// Called once from `main`'s `.setup()`, sharing the same `FeedStore` that
+/// `publish_feed_item` writes to.
+pub fn serve_feed_generator(store: Arc<FeedStore>) {
+    let app = Router::new()
+        .route("/xrpc/app.bsky.feed.getFeedSkeleton", get(get_feed_skeleton))
+        .with_state(store);
+
+    tauri::async_runtime::spawn(async move {
+        let listener = match tokio::net::TcpListener::bind(FEED_GENERATOR_ADDR).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                eprintln!("feed generator failed to bind {FEED_GENERATOR_ADDR}: {e}");
+                return;
+            }
+        };
+        if let Err(e) = axum::serve(listener, app).await {
+            eprintln!("feed generator server error: {e}");
+        }
+    });
+}
@@ -0,0 +1,35 @@
+// src-tauri/src/social/types.rs — feed-generator wire types.
+
+use serde::{Deserialize, Serialize};
+
+/// An item the user has published, identified by its AT-URI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedItem {
+    pub at_uri: String,
+}
+
+/// One entry of a `getFeedSkeleton` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedSkeletonItem {
+    pub post: String,
+}
+
+/// The `app.bsky.feed.getFeedSkeleton` XRPC response shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedSkeleton {
+    pub feed: Vec<FeedSkeletonItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedSkeletonQuery {
+    #[serde(default)]
+    pub cursor: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    50
+}